@@ -53,5 +53,6 @@ pub trait BackendRemoteData: core::fmt::Debug {
     fn get_data(&self) -> BackendDataRef<'_>;
     fn get_data_mut(&mut self) -> BackendDataMutRef<'_>;
 
+    #[allow(dead_code)]
     fn is_alive(&self) -> bool;
 }
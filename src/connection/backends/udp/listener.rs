@@ -1,9 +1,9 @@
 use std::collections::HashMap;
 use std::net::{IpAddr, Ipv4Addr, SocketAddr, UdpSocket};
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 
 use crate::packet_parsing::types::*;
-use crate::packet_parsing::{client, server};
+use crate::packet_parsing::{client, server, ParseError};
 use crate::connection::listener::{Listener, RemoteMap};
 use crate::connection::remote_client::{Client, BDataContainer, PacketBuffered, RemoteClientWrapper, Server};
 
@@ -16,6 +16,17 @@ pub struct UdpServer {
     pub(super) addr_to_mac: HashMap<SocketAddr, MacAddress>,
     pub(super) buf: [u8; 256],
     pub(super) local_addr: SocketAddr,
+    pub(super) malformed_counts: HashMap<SocketAddr, MalformedCount>,
+}
+
+// How long an address has to stop sending malformed datagrams before its
+// count is forgotten
+const MALFORMED_FORGET_AFTER: Duration = Duration::from_secs(60);
+
+#[derive(Debug)]
+pub(super) struct MalformedCount {
+    count: usize,
+    last_seen: SystemTime
 }
 
 
@@ -30,13 +41,8 @@ impl UdpServer {
         let insert_result = client.insert_bdata(BackendType::Udp(self.local_addr), Box::new(udp_client));
         
         if let Ok(result) = insert_result {
-            let data = result.get_data_mut();
-
-            if let BackendDataMutRef::Udp(udp ) = data {
-                return Some(udp);
-            }else{
-                panic!("Inserted a Udp backendtype, received a different one");
-            }
+            let BackendDataMutRef::Udp(udp) = result.get_data_mut();
+            return Some(udp);
         }
         
         return None;
@@ -62,9 +68,7 @@ impl UdpServer {
         let wrap: &mut RemoteClientWrapper = client_map.entry(mac_key).or_insert(RemoteClientWrapper::Client(client));
 
         // Insert UdpClient into wrapper
-        if let Some(udp) = self.insert_udp_wrapper(addr, wrap){
-        
-        }else{
+        if self.insert_udp_wrapper(addr, wrap).is_none() {
             println!("Failed to insert UdpClient..");
         }
             
@@ -93,9 +97,7 @@ impl UdpServer {
         let wrap = client_map.entry(mac_key).or_insert(RemoteClientWrapper::Server(server));
 
         // Insert UdpClient into wrapper
-        if let Some(udp) = self.insert_udp_wrapper(addr, wrap){
-        
-        }else{
+        if self.insert_udp_wrapper(addr, wrap).is_none() {
             println!("Failed to insert UdpClient..");
         }
 
@@ -131,6 +133,7 @@ impl UdpServer {
             addr_to_mac: Default::default(),
             buf: [0u8; 256],
             local_addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), port),
+            malformed_counts: Default::default(),
         };
         
         if let Ok(addr) = srv.socket.local_addr() {
@@ -142,12 +145,45 @@ impl UdpServer {
         Ok(srv)
     }
 
+    // Returns the number of malformed datagrams received from the given address
+    #[allow(dead_code)]
+    pub fn malformed_count(&self, addr: &SocketAddr) -> usize {
+        self.malformed_counts.get(addr).map_or(0, |m| m.count)
+    }
+
+    fn report_malformed(&mut self, addr: SocketAddr, err: &ParseError) {
+        let now = SystemTime::now();
+        if !self.malformed_counts.contains_key(&addr) {
+            self.forget_idle_malformed(now);
+        }
+
+        let malformed = self.malformed_counts.entry(addr).or_insert(MalformedCount {
+            count: 0,
+            last_seen: now
+        });
+        malformed.count += 1;
+        malformed.last_seen = now;
+
+        println!("Malformed packet from {} ({} so far): {}", addr, malformed.count, err);
+    }
+
+    // Drops the counts of addresses that have gone quiet
+    pub(super) fn forget_idle_malformed(&mut self, now: SystemTime) {
+        self.malformed_counts.retain(|_, m| now.duration_since(m.last_seen).unwrap_or_default() < MALFORMED_FORGET_AFTER);
+    }
+
     pub fn receive_packet(&mut self, size: usize, addr: SocketAddr, client_map: &mut RemoteMap) -> Option<()> {        
-        if let Some(mac) = self.addr_to_mac.get(&addr) {
-            if let Some(rc) = client_map.get_mut(mac) {
+        if let Some(mac) = self.addr_to_mac.get(&addr).cloned() {
+            if let Some(rc) = client_map.get_mut(&mac) {
                 match rc {
                     RemoteClientWrapper::Client(client) => {
-                        let dec = server::parse_slice(&self.buf[0..size])?;
+                        let dec = match server::parse_slice(&self.buf[0..size]) {
+                            Ok(dec) => dec,
+                            Err(e) => {
+                                self.report_malformed(addr, &e);
+                                return None;
+                            }
+                        };
                         client.receive_packet(dec);
                         
                         if let Some(udp) = self.get_udp_client_mut(client) {
@@ -157,7 +193,13 @@ impl UdpServer {
                     },
 
                     RemoteClientWrapper::Server(server) => {
-                        let dec = client::parse_slice(&self.buf[0..size])?;
+                        let dec = match client::parse_slice(&self.buf[0..size]) {
+                            Ok(dec) => dec,
+                            Err(e) => {
+                                self.report_malformed(addr, &e);
+                                return None;
+                            }
+                        };
                         server.receive_packet(dec);
                         
                         if let Some(udp) = self.get_udp_client_mut(server) {
@@ -168,15 +210,13 @@ impl UdpServer {
                 }
             }
         }else{
-            let server_attempt = server::parse_slice(&self.buf[0..size]);
-
-            if let Some(a) = server_attempt {
-                if let server::PacketType::Handshake(_, dat) = a {
+            match server::parse_slice(&self.buf[0..size]) {
+                Ok(server::PacketType::Handshake(_, dat)) => {
                     self.handle_client2server_handshake(dat, size, addr, client_map);
-                }
+                },
+                Ok(_) => {},
+                Err(e) => self.report_malformed(addr, &e)
             }
-
-            
         }
 
         None
@@ -201,11 +241,15 @@ impl Listener for UdpServer {
     }
 
     fn flush(&mut self, client_map: &mut RemoteMap) -> usize {
+        if !self.malformed_counts.is_empty() {
+            self.forget_idle_malformed(SystemTime::now());
+        }
+
         let mut num_packets: usize = 0;
 
         for (_, client) in client_map.iter_mut() {
             let outgoing = client.get_outgoing_packets();
-            if outgoing.len() == 0 { continue; }
+            if outgoing.is_empty() { continue; }
                 
             if let Some(udp) = self.get_udp_client(client) {
                 if udp.srv_addr != self.local_addr {
@@ -1,4 +1,5 @@
 #[cfg(test)]
+#[allow(clippy::needless_late_init)]
 mod udp_tests {
     use core::time;
     use std::str::FromStr;
@@ -99,6 +100,7 @@ mod udp_tests {
 
         let bdata = remote_server.find_bdata(&BackendType::Udp(srv.local_addr)).expect("Backend data should exist in new UDP client");
         let udp_ref;
+        #[allow(unreachable_patterns)]
         match bdata {
             BackendDataRef::Udp(udp) => {
                 assert_eq!(udp.srv_addr, srv.local_addr, "The server address of the UdpClient should match the true server address");
@@ -200,6 +202,27 @@ mod udp_tests {
     }
 
 
+    #[test]
+    fn test_malformed_packets_counted(){
+        let mut server_srv = obtain_server(6);
+        let mut server_map = obtain_blank_remote_map();
+
+        let sock = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let sock_addr = sock.local_addr().unwrap();
+
+        sock.send_to(&[0xFF, 0xFF, 0xFF, 0xFF, 0x00], "127.0.0.1:16006").unwrap();
+        sock.send_to(&[0x00, 0x00], "127.0.0.1:16006").unwrap();
+
+        assert!(ensure_received(&mut server_srv, &mut server_map, 2),
+            "Both malformed datagrams should be received");
+
+        assert_eq!(server_srv.malformed_count(&sock_addr), 2, "Both datagrams should be counted as malformed");
+        assert_eq!(server_map.len(), 0, "Malformed datagrams should not create remotes");
+
+        server_srv.forget_idle_malformed(SystemTime::now() + Duration::from_secs(61));
+        assert_eq!(server_srv.malformed_count(&sock_addr), 0, "The count should be forgotten once the address goes quiet");
+    }
+
     #[test]
     fn test_client_alive(){
         let alive_client = UdpClient {
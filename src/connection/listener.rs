@@ -19,13 +19,11 @@ pub trait Listener {
 
 impl ListenerCollection {
     pub fn clients(&self) -> std::collections::hash_map::Values<'_, MacAddress, RemoteClientWrapper> {
-        let values = self.remotes.values().into_iter();
-        return values;
+        return self.remotes.values();
     }
 
     pub fn clients_mut(&mut self) -> std::collections::hash_map::ValuesMut<'_, MacAddress, RemoteClientWrapper> {
-        let values = self.remotes.values_mut().into_iter();
-        return values;
+        return self.remotes.values_mut();
     }
 
     pub fn receive(&mut self) {
@@ -39,7 +37,7 @@ impl ListenerCollection {
             server.flush(&mut self.remotes);
         }
 
-        for (_, remote) in &mut self.remotes {
+        for remote in self.remotes.values_mut() {
             remote.clear_outgoing();
         }
     }
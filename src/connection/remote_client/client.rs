@@ -26,7 +26,7 @@ impl Client {
     }
 
     pub fn send_packet(&mut self, pkt: &client::PacketType) {
-        if let Some(bytes) = client::to_bytes(pkt) {
+        if let Ok(bytes) = client::to_bytes(pkt) {
             self.remote.send_packet(bytes);
         }
     }
@@ -52,6 +52,26 @@ impl Client {
         self.tracker.last_heartbeat = SystemTime::now();
     }
 
+    fn handle_rotation_data(&mut self, id: SensorID, data: server::RotationDataType) {
+        match data {
+            server::RotationDataType::Normal(q, _) => self.update_rotation(id, q),
+            server::RotationDataType::Correction(q, _) => {
+                self.tracker.get_sensor_or_default(id).last_correction = Some(q);
+            }
+        }
+    }
+
+    fn handle_sensor_info(&mut self, id: SensorID, info: SensorInfoData) {
+        self.tracker.get_sensor_or_default(id).status = Some(info.status);
+
+        // The firmware keeps re-sending sensor info until we acknowledge it
+        let response = client::PacketType::Other(
+            client::OPacketType::SensorInfo(id, info.status != 0)
+        );
+
+        self.send_packet(&response);
+    }
+
     pub fn receive_packet(&mut self, pkt: server::PacketType) {
         self.update_heartbeat_time();
         // TODO: use packet id...
@@ -63,22 +83,30 @@ impl Client {
             server::PacketType::Handshake(_, h) => self.handle_handshake(h),
             server::PacketType::Accelerometer(_, _) => {},
             server::PacketType::Magnetometer(_, _) => {},
-            server::PacketType::RawCalibration(_, _) => todo!(),
-            server::PacketType::GyroCalibration(_, _) => todo!(),
-            server::PacketType::Config(_, _) => todo!(),
-            server::PacketType::RawMagnetometer(_, _) => todo!(),
-            server::PacketType::Ping(_, _) => todo!(),
-            server::PacketType::Serial(_, _) => todo!(),
+            server::PacketType::RawCalibration(_, c) => self.tracker.raw_calibration = Some(c),
+            server::PacketType::GyroCalibration(_, c) => self.tracker.gyro_calibration = Some(c),
+            server::PacketType::Config(_, c) => self.tracker.config = Some(c),
+            server::PacketType::RawMagnetometer(_, v) => {
+                self.tracker.get_sensor_or_default(0).raw_magnetometer = Some(v);
+            },
+            server::PacketType::Ping(_, id) => self.tracker.last_ping = Some(id),
+            server::PacketType::Serial(_, s) => {
+                let SerialData(line) = s;
+                self.tracker.push_serial_line(line.to_string());
+            },
             server::PacketType::Battery(_, lvl) => {
                 let BatteryData(f) = lvl;
-                println!("Battery {}", f);
+                self.tracker.battery_level = Some(f);
             },
-            server::PacketType::Tap(_, _, _) => todo!(),
-            server::PacketType::ResetReason(_, _) => todo!(),
-            server::PacketType::SensorInfo(_, _, _) => todo!(),
+            server::PacketType::Tap(_, id, tap) => self.tracker.last_tap = Some((id, tap)),
+            server::PacketType::ResetReason(_, r) => self.tracker.reset_reason = Some(r),
+            server::PacketType::SensorInfo(_, id, info) => self.handle_sensor_info(id, info),
             server::PacketType::Rotation2(_, q) => self.update_rotation(1, q),
-            server::PacketType::RotationData(_, _, _) => todo!(),
-            server::PacketType::MagnetometerAccuracy(_, _, _) => todo!(),
+            server::PacketType::RotationData(_, id, data) => self.handle_rotation_data(id, data),
+            server::PacketType::MagnetometerAccuracy(_, id, acc) => {
+                let MagnetometerAccuracyData(f) = acc;
+                self.tracker.get_sensor_or_default(id).magnetometer_accuracy = Some(f);
+            },
         }
     }
 }
@@ -13,6 +13,7 @@
 
 pub mod client;
 pub mod server;
+mod tests;
 
 pub use client::*;
 pub use server::*;
@@ -57,6 +58,10 @@ impl RemoteClient {
         }
     }
 
+    #[allow(dead_code)]
+    pub fn get_mac(&self) -> &MacAddress {
+        &self.mac
+    }
 }
 
 impl PacketBuffered for RemoteClient {
@@ -83,7 +88,7 @@ impl BDataContainer for RemoteClient {
     }
     
     fn insert_bdata(&mut self, t: BackendType, d: Box<dyn BackendRemoteData>) -> Result<&mut Box<dyn BackendRemoteData>, BDataInsertionFailure> {
-        if let Some(_) = self.clients.get(&t) {
+        if self.clients.contains_key(&t) {
             return Err(BDataInsertionFailure::AlreadyExists);
         }
         let entry = self.clients.entry(t);
@@ -100,7 +105,9 @@ pub trait RemoteClientContainer {
 }
 
 
+// Client holds all of the tracker state, which makes it far larger than Server
 #[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
 pub enum RemoteClientWrapper {
     Client(Client),
     Server(Server)
@@ -2,39 +2,101 @@
 // Sends client::PacketType to us
 // We send server::PacketType to them
 
-// TODO: proper implementation
 // TODO: have heartbeat
 
 use super::*;
 use super::super::backends::enums::*;
 use crate::packet_parsing::{server, client, types::*};
 
+// Requests made by the server that the embedding code should act upon, as
+// the emulated tracker has no hardware of its own.
+#[derive(Debug, PartialEq)]
+pub enum ServerEvent {
+    Vibrate(client::VibrateData),
+    Calibrate,
+    Blink,
+    ConfigChanged(DeviceConfig),
+    SensorInfoAcknowledged(SensorID, client::SensorStateNotified)
+}
+
 #[derive(Debug)]
 pub struct Server {
     remote: RemoteClient,
+    config: DeviceConfig,
+    packet_id: PacketID,
+    events: Vec<ServerEvent>
 }
 
 impl Server {
     pub fn new(mac: MacAddress) -> Server {
-        return Server { remote: RemoteClient::new(mac) }
+        return Server {
+            remote: RemoteClient::new(mac),
+            config: DeviceConfig::default(),
+            packet_id: 0,
+            events: Default::default()
+        }
     }
 
     pub fn send_packet(&mut self, pkt: &server::PacketType) {
-        if let Some(bytes) = server::to_bytes(pkt) {
+        if let Ok(bytes) = server::to_bytes(pkt) {
             self.remote.send_packet(bytes);
         }
     }
 
+    // Returns a new id for an outgoing packet. Packet ids must increase for
+    // the remote to accept them.
+    pub fn next_packet_id(&mut self) -> PacketID {
+        self.packet_id += 1;
+        return self.packet_id;
+    }
+
+    #[allow(dead_code)]
+    pub fn get_config(&self) -> &DeviceConfig {
+        &self.config
+    }
+
+    #[allow(dead_code)]
+    pub fn set_config(&mut self, config: DeviceConfig) {
+        self.config = config;
+    }
+
+    // Returns the events received since the last call
+    pub fn take_events(&mut self) -> Vec<ServerEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    fn handle_command(&mut self, cmd: client::CommandType) {
+        match cmd {
+            client::CommandType::Calibrate => self.events.push(ServerEvent::Calibrate),
+            client::CommandType::Blink => self.events.push(ServerEvent::Blink),
+            client::CommandType::SendConfig => {
+                let id = self.next_packet_id();
+                self.send_packet(&server::PacketType::Config(id, self.config.clone()));
+            }
+        }
+    }
+
     pub fn receive_packet(&mut self, pkt: client::PacketType) {
         match pkt {
             client::PacketType::Handshake(hnd) => self.handle_handshake(hnd),
             client::PacketType::Other(o) => match o {
-                client::OPacketType::Heartbeat(_) => todo!(),
-                client::OPacketType::Vibrate(_) => todo!(),
-                client::OPacketType::Command(_) => todo!(),
-                client::OPacketType::Config(_) => todo!(),
-                client::OPacketType::Ping(_) => todo!(),
-                client::OPacketType::SensorInfo(_, _) => todo!(),
+                client::OPacketType::Heartbeat(_) => {
+                    let id = self.next_packet_id();
+                    self.send_packet(&server::PacketType::Heartbeat(id));
+                },
+                client::OPacketType::Vibrate(v) => self.events.push(ServerEvent::Vibrate(v)),
+                client::OPacketType::Command(cmd) => self.handle_command(cmd),
+                client::OPacketType::Config(c) => {
+                    self.config = c.clone();
+                    self.events.push(ServerEvent::ConfigChanged(c));
+                },
+                client::OPacketType::Ping(ping) => {
+                    let id = self.next_packet_id();
+                    self.send_packet(&server::PacketType::Ping(id, ping));
+                },
+                client::OPacketType::SensorInfo(sensor, notified) => {
+                    self.events.push(ServerEvent::SensorInfoAcknowledged(sensor, notified));
+                }
             }
         }
     }
@@ -59,7 +121,7 @@ impl Server {
     }
 
     pub fn handle_handshake(&mut self, hnd: client::ClientHandshake) {
-        println!("Server handshake, version {}", hnd.version - ('0' as u8));
+        println!("Server handshake, version {}", hnd.get_version());
     }
 }
 
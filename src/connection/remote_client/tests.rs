@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod remote_client_tests {
+    use crate::connection::remote_client::{Client, PacketBuffered, Server, ServerEvent};
+    use crate::packet_parsing::types::*;
+    use crate::packet_parsing::{client, server};
+
+    fn obtain_client() -> Client {
+        Client::new(&HandshakeData {
+            board_type: 0,
+            imu_type: 0,
+            mcu_type: 0,
+            imu_info: ImuInfo(0, 0, 0),
+            firmware_build: 0,
+            firmware: FirmwareString::default(),
+            mac_address: MacAddress(1, 2, 3, 4, 5, 6)
+        })
+    }
+
+    #[test]
+    fn test_client_stores_tracker_state(){
+        let mut c = obtain_client();
+
+        c.receive_packet(server::PacketType::Battery(1, BatteryData(0.75)));
+        c.receive_packet(server::PacketType::Tap(2, 3, TapData(12)));
+        c.receive_packet(server::PacketType::ResetReason(3, ResetReasonData(4)));
+        c.receive_packet(server::PacketType::Ping(4, 1234));
+        c.receive_packet(server::PacketType::MagnetometerAccuracy(5, 2, MagnetometerAccuracyData(1.5)));
+
+        let tracker = c.get_tracker();
+        assert_eq!(tracker.battery_level, Some(0.75));
+        assert_eq!(tracker.last_tap, Some((3, TapData(12))));
+        assert_eq!(tracker.reset_reason, Some(ResetReasonData(4)));
+        assert_eq!(tracker.last_ping, Some(1234));
+        assert_eq!(tracker.sensors.get(&2).unwrap().magnetometer_accuracy, Some(1.5));
+    }
+
+    #[test]
+    fn test_client_routes_rotation_data(){
+        let mut c = obtain_client();
+        let quat = Quaternion { x: 0.1, y: 0.2, z: 0.3, w: 0.4 };
+
+        c.receive_packet(server::PacketType::RotationData(1, 4,
+            server::RotationDataType::Normal(quat, CalibrationInfo(0))));
+
+        assert_eq!(c.get_tracker().sensors.get(&4).unwrap().last_quat, quat,
+            "RotationData should update the sensor it names");
+    }
+
+    #[test]
+    fn test_client_acknowledges_sensor_info(){
+        let mut c = obtain_client();
+
+        c.receive_packet(server::PacketType::SensorInfo(1, 2, SensorInfoData { status: 1 }));
+
+        assert_eq!(c.get_tracker().sensors.get(&2).unwrap().status, Some(1));
+
+        let outgoing = c.get_outgoing_packets();
+        assert_eq!(outgoing.len(), 1, "Sensor info should be acknowledged");
+        assert_eq!(client::parse_slice(&outgoing[0]).unwrap(),
+            client::PacketType::Other(client::OPacketType::SensorInfo(2, true)));
+    }
+
+    #[test]
+    fn test_server_answers_ping_and_heartbeat(){
+        let mut s = Server::new(MacAddress(1, 2, 3, 4, 5, 6));
+
+        s.receive_packet(client::PacketType::Other(client::OPacketType::Ping(77)));
+        s.receive_packet(client::PacketType::Other(client::OPacketType::Heartbeat(
+            client::HeartbeatToClient { extra: 0 })));
+
+        let outgoing = s.get_outgoing_packets();
+        assert_eq!(outgoing.len(), 2, "Ping and heartbeat should both be answered");
+
+        match server::parse_slice(&outgoing[0]).unwrap() {
+            server::PacketType::Ping(_, id) => assert_eq!(id, 77, "Ping id should be echoed"),
+            p => panic!("Expected ping, got {:?}", p)
+        }
+
+        match server::parse_slice(&outgoing[1]).unwrap() {
+            server::PacketType::Heartbeat(_) => {},
+            p => panic!("Expected heartbeat, got {:?}", p)
+        }
+    }
+
+    #[test]
+    fn test_server_sends_config_on_request(){
+        let mut s = Server::new(MacAddress(1, 2, 3, 4, 5, 6));
+
+        let config = DeviceConfig {
+            device_id: 42,
+            ..Default::default()
+        };
+        s.set_config(config.clone());
+
+        s.receive_packet(client::PacketType::Other(client::OPacketType::Command(
+            client::CommandType::SendConfig)));
+
+        let outgoing = s.get_outgoing_packets();
+        assert_eq!(outgoing.len(), 1, "SendConfig should be answered with the config");
+
+        match server::parse_slice(&outgoing[0]).unwrap() {
+            server::PacketType::Config(_, c) => assert_eq!(c, config),
+            p => panic!("Expected config, got {:?}", p)
+        }
+    }
+
+    #[test]
+    fn test_server_raises_command_events(){
+        let mut s = Server::new(MacAddress(1, 2, 3, 4, 5, 6));
+
+        s.receive_packet(client::PacketType::Other(client::OPacketType::Command(
+            client::CommandType::Blink)));
+        s.receive_packet(client::PacketType::Other(client::OPacketType::Command(
+            client::CommandType::Calibrate)));
+        s.receive_packet(client::PacketType::Other(client::OPacketType::Vibrate(
+            client::VibrateData { duration_seconds: 1.0, frequency: 2.0, amplitude: 3.0 })));
+
+        assert_eq!(s.take_events(), vec![
+            ServerEvent::Blink,
+            ServerEvent::Calibrate,
+            ServerEvent::Vibrate(client::VibrateData {
+                duration_seconds: 1.0, frequency: 2.0, amplitude: 3.0
+            })
+        ]);
+
+        assert!(s.take_events().is_empty(), "Events should only be returned once");
+        assert!(s.get_outgoing_packets().is_empty(), "Commands should not be answered");
+    }
+}
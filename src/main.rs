@@ -1,12 +1,15 @@
 #![deny(rust_2018_idioms)]
 #![deny(rust_2018_compatibility)]
+// return statements are used for clarity, and deku generates code clippy
+// dislikes
+#![allow(clippy::needless_return, clippy::manual_div_ceil)]
 
 mod packet_parsing;
 mod connection;
 mod tracker;
 mod types;
 
-use std::{any::Any, env, net::SocketAddr, str::FromStr, time::SystemTime};
+use std::{env, net::SocketAddr, str::FromStr, time::SystemTime};
 
 use connection::{backends::{enums::BackendListener, udp::UdpServer}, remote_client::RemoteClientWrapper};
 use types::Quaternion;
@@ -17,7 +20,7 @@ use crate::{connection::{listener::*}, packet_parsing::server};
 fn run_client_example(ip: String) -> Option<()> {
     let mut collection = ListenerCollection::default();
 
-    let mut udp = UdpServer::new(65000).unwrap();
+    let udp = UdpServer::new(65000).unwrap();
     collection.add_server(BackendListener::Udp(udp));
 
 
@@ -26,20 +29,27 @@ fn run_client_example(ip: String) -> Option<()> {
 
     let listener = &mut collection.listeners[0];
 
-    if let BackendListener::Udp(udp) = listener {
-        udp.connect_to_server(SocketAddr::from_str(&ip).expect("Invalid IP address"), &mut collection.remotes);
-    }
+    let BackendListener::Udp(udp) = listener;
+    udp.connect_to_server(SocketAddr::from_str(&ip).expect("Invalid IP address"), &mut collection.remotes);
     
 
     loop {
         collection.receive();
+
+        for client in collection.clients_mut() {
+            if let RemoteClientWrapper::Server(srv) = client {
+                for event in srv.take_events() {
+                    println!("Server requested {:?}", event);
+                }
+            }
+        }
+
         collection.flush();
 
         let curr_print = SystemTime::now();
 
         if curr_print.duration_since(last_print).unwrap().as_millis() > 500 {
-            let mut i = 0;
-            for client in collection.clients_mut() {
+            for (i, client) in collection.clients_mut().enumerate() {
                 if let RemoteClientWrapper::Server(srv) = client {
                     println!("[{}]: {:?}", i, srv);
 
@@ -51,14 +61,11 @@ fn run_client_example(ip: String) -> Option<()> {
                         w: 1.1
                     }));
                 }
-                i = i + 1;
             }
 
             last_print = curr_print;
         }
     }
-
-    return None;
 }
 
 
@@ -83,12 +90,10 @@ fn run_server_example() -> Option<()> {
         let curr_print = SystemTime::now();
 
         if curr_print.duration_since(last_print).unwrap().as_millis() > 500 {
-            let mut i = 0;
-            for client in collection.clients() {
+            for (i, client) in collection.clients().enumerate() {
                 if let RemoteClientWrapper::Client(client) = client {
                     println!("[{}]: {:?}", i, client.get_tracker().sensors);
                 }
-                i = i + 1;
             }
 
             last_print = curr_print;
@@ -11,4 +11,13 @@ pub const COMMAND               :u16 =  4;
 // shared with packet_parsing::server::ids
 pub const CONFIG                :u16 =  8;
 pub const PING                  :u16 =  10;
-pub const SENSOR_INFO           :u16 =  15;
\ No newline at end of file
+pub const SENSOR_INFO           :u16 =  15;
+
+// Takes the full 4 byte id (F_OTHER, magic and the u16 id)
+pub fn is_known(id: u32) -> bool {
+	if id > (u16::MAX as u32) {
+		return false;
+	}
+
+	matches!(id as u16, HEARTBEAT | VIBRATE | COMMAND | CONFIG | PING | SENSOR_INFO)
+}
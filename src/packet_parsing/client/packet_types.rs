@@ -1,5 +1,3 @@
-use crate::connection::remote_client::Client;
-
 use super::ids;
 use super::super::types::*;
 
@@ -14,11 +12,11 @@ pub struct ClientHandshake {
 }
 
 impl ClientHandshake {
-    pub fn get_version(&self) -> u8 { self.version - ('0' as u8) }
+    pub fn get_version(&self) -> u8 { self.version - b'0' }
     
     pub fn with_version(version: u8) -> ClientHandshake {
         ClientHandshake { 
-            version: version + ('0' as u8)
+            version: version + b'0'
         }
     }
 }
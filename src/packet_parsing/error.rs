@@ -0,0 +1,53 @@
+use deku::DekuError;
+
+// Describes why a datagram could not be turned into a packet (or the other
+// way around)
+#[derive(PartialEq, Debug)]
+pub enum ParseError {
+	// The packet id is not one we know how to decode
+	UnknownPacketId(u32),
+
+	// The payload ended before the packet was complete
+	Truncated,
+
+	// The packet was decoded, but this many bytes were left over
+	TrailingBytes(usize),
+
+	// A StringWithLength did not contain valid UTF-8
+	InvalidUtf8,
+
+	// Anything else deku complained about (bad enum ids, magic, etc)
+	Malformed(String)
+}
+
+impl std::fmt::Display for ParseError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			ParseError::UnknownPacketId(id) => write!(f, "unknown packet id {}", id),
+			ParseError::Truncated => f.write_str("packet is truncated"),
+			ParseError::TrailingBytes(n) => write!(f, "{} trailing bytes after packet", n),
+			ParseError::InvalidUtf8 => f.write_str("string is not valid UTF-8"),
+			ParseError::Malformed(s) => write!(f, "malformed packet: {}", s)
+		}
+	}
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<DekuError> for ParseError {
+	fn from(e: DekuError) -> ParseError {
+		match e {
+			DekuError::Incomplete(_) => ParseError::Truncated,
+			e => ParseError::Malformed(e.to_string())
+		}
+	}
+}
+
+// Reads the big-endian u32 at the start of a packet
+pub(super) fn peek_u32(b: &[u8]) -> Result<u32, ParseError> {
+	if b.len() < 4 {
+		return Err(ParseError::Truncated);
+	}
+
+	Ok(u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+}
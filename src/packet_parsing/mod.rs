@@ -1,3 +1,6 @@
 pub mod server;
 pub mod client;
 pub mod types;
+pub mod error;
+
+pub use error::ParseError;
@@ -10,35 +10,50 @@ pub use packet_types::*;
 use deku::{DekuContainerRead, DekuContainerWrite};
 use bytes::Buf;
 
+use super::error::{ParseError, peek_u32};
+
+fn validate(pkt: &PacketType) -> Result<(), ParseError> {
+	match pkt {
+		PacketType::Handshake(_, h) => h.firmware.0.validate(),
+		PacketType::Serial(_, s) => s.0.validate(),
+		_ => Ok(())
+	}
+}
+
+// Bytes left over after the packet are ignored, use parse_slice_strict to
+// reject them instead
 #[allow(dead_code)]
-pub fn parse_slice(b: &[u8]) -> Option<PacketType> {
-	let result = PacketType::from_bytes((b, 0));
-
-	match result {
-		Ok((_rest, result)) => {
-			Some(result)
-		}
-		Err(_) => {
-			None
-		}
+pub fn parse_slice(b: &[u8]) -> Result<PacketType, ParseError> {
+	decode(b, false)
+}
+
+#[allow(dead_code)]
+pub fn parse_slice_strict(b: &[u8]) -> Result<PacketType, ParseError> {
+	decode(b, true)
+}
+
+fn decode(b: &[u8], strict: bool) -> Result<PacketType, ParseError> {
+	let id = peek_u32(b)?;
+	if !ids::is_known(id) {
+		return Err(ParseError::UnknownPacketId(id));
+	}
+
+	let ((rest, _), result) = PacketType::from_bytes((b, 0))?;
+	if strict && !rest.is_empty() {
+		return Err(ParseError::TrailingBytes(rest.len()));
 	}
+
+	validate(&result)?;
+
+	Ok(result)
 }
 
 #[allow(dead_code)]
-pub fn parse_buf(bb: &mut dyn Buf) -> Option<PacketType> {
+pub fn parse_buf(bb: &mut dyn Buf) -> Result<PacketType, ParseError> {
     parse_slice(bb.chunk())
 }
 
 #[allow(dead_code)]
-pub fn to_bytes(pk: &PacketType) -> Option<Vec<u8>> {
-    let result = pk.to_bytes();
-
-    match result {
-		Ok(data) => {
-			Some(data)
-		}
-		Err(_) => {
-			None
-		}
-	}
-}
\ No newline at end of file
+pub fn to_bytes(pk: &PacketType) -> Result<Vec<u8>, ParseError> {
+    Ok(pk.to_bytes()?)
+}
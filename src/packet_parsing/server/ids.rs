@@ -18,4 +18,8 @@ pub const RESET_REASON			:u32 =	14;
 pub const SENSOR_INFO			:u32 =	15;
 pub const ROTATION_2			:u32 =	16;
 pub const ROTATION_DATA			:u32 =	17;
-pub const MAGNETOMETER_ACCURACY	:u32 =	18;
\ No newline at end of file
+pub const MAGNETOMETER_ACCURACY	:u32 =	18;
+
+pub fn is_known(id: u32) -> bool {
+	id <= MAGNETOMETER_ACCURACY
+}
@@ -1,15 +1,17 @@
 #[cfg(test)]
+#[allow(clippy::excessive_precision, clippy::approx_constant)]
 mod parse_tests {
     use crate::packet_parsing::server::parse_slice;
 	use crate::packet_parsing::server::parse_buf;
 	use crate::packet_parsing::server::ids;
 	use crate::packet_parsing::server::*;
 	use crate::packet_parsing::types::*;
+	use crate::packet_parsing::ParseError;
 	use bytes::{BytesMut, BufMut, Buf};
 
 
 	fn assert_encoded_is_equal(buf: &BytesMut, parsed: &PacketType){
-		let encoded = to_bytes(&parsed).unwrap();
+		let encoded = to_bytes(parsed).unwrap();
 
 		assert!(buf.remaining() > 0, 
 			"This test is invalid - the original buffer is blank");
@@ -129,7 +131,7 @@ mod parse_tests {
 		assert_encoded_is_equal(&buf, &parsed_packet);
 
 		let failure = super::super::super::client::parse_buf(&mut buf);
-		assert_eq!(failure, None, "Parsing the server handshake packet in client should fail");
+		assert!(failure.is_err(), "Parsing the server handshake packet in client should fail");
 	}
 	
 	#[test]
@@ -152,14 +154,14 @@ mod parse_tests {
 		buf.put_i32(999372i32);
 
 		buf.put_u8(8);
-		buf.put_u8('H' as u8);
-		buf.put_u8('e' as u8);
-		buf.put_u8('l' as u8);
-		buf.put_u8('l' as u8);
-		buf.put_u8('o' as u8);
-		buf.put_u8('d' as u8);
-		buf.put_u8('e' as u8);
-		buf.put_u8('r' as u8);
+		buf.put_u8(b'H');
+		buf.put_u8(b'e');
+		buf.put_u8(b'l');
+		buf.put_u8(b'l');
+		buf.put_u8(b'o');
+		buf.put_u8(b'd');
+		buf.put_u8(b'e');
+		buf.put_u8(b'r');
 
 
 		buf.put_u8(0x10);
@@ -190,7 +192,7 @@ mod parse_tests {
 		assert_encoded_is_equal(&buf, &parsed_packet);
 
 		let failure = super::super::super::client::parse_buf(&mut buf);
-		assert_eq!(failure, None, "Parsing the server handshake packet in client should fail");
+		assert!(failure.is_err(), "Parsing the server handshake packet in client should fail");
 	}
 
 	#[test]
@@ -351,16 +353,16 @@ mod parse_tests {
 		buf.put_u64(64u64);
 
 		buf.put_u8(10);
-		buf.put_u8('A' as u8);
-		buf.put_u8('A' as u8);
-		buf.put_u8('A' as u8);
-		buf.put_u8('A' as u8);
-		buf.put_u8('A' as u8);
-		buf.put_u8('A' as u8);
-		buf.put_u8('A' as u8);
-		buf.put_u8('A' as u8);
-		buf.put_u8('A' as u8);
-		buf.put_u8('A' as u8);
+		buf.put_u8(b'A');
+		buf.put_u8(b'A');
+		buf.put_u8(b'A');
+		buf.put_u8(b'A');
+		buf.put_u8(b'A');
+		buf.put_u8(b'A');
+		buf.put_u8(b'A');
+		buf.put_u8(b'A');
+		buf.put_u8(b'A');
+		buf.put_u8(b'A');
 
 		let parsed_packet = parse_buf(&mut buf).unwrap();
 
@@ -593,7 +595,7 @@ mod parse_tests {
 
 		let parsed_packet = parse_buf(&mut buf);
 
-		assert_eq!(parsed_packet, None);
+		assert!(matches!(parsed_packet, Err(ParseError::UnknownPacketId(_))));
 	}
 
 	#[test]
@@ -616,6 +618,54 @@ mod parse_tests {
 
 		let parsed_packet = parse_buf(&mut buf);
 
-		assert_eq!(parsed_packet, None);
+		assert!(matches!(parsed_packet, Err(ParseError::Malformed(_))));
+	}
+
+	#[test]
+	fn test_parse_truncated(){
+		let mut buf = BytesMut::with_capacity(128);
+
+		buf.put_u32(ids::ROTATION);
+		buf.put_u64(64u64);
+
+		buf.put_f32(5.12f32);
+		buf.put_f32(1.28f32);
+
+		assert_eq!(parse_buf(&mut buf), Err(ParseError::Truncated));
+
+		let mut buf = BytesMut::with_capacity(128);
+		buf.put_u16(0);
+
+		assert_eq!(parse_buf(&mut buf), Err(ParseError::Truncated));
+	}
+
+	#[test]
+	fn test_parse_trailing_bytes(){
+		let mut buf = BytesMut::with_capacity(128);
+
+		buf.put_u32(ids::PING);
+		buf.put_u64(64u64);
+
+		buf.put_i32(90000i32);
+		buf.put_u8(1);
+		buf.put_u8(2);
+
+		assert_eq!(parse_slice_strict(&buf), Err(ParseError::TrailingBytes(2)));
+		assert!(matches!(parse_buf(&mut buf), Ok(PacketType::Ping(_, _))), "Trailing bytes should be ignored by default");
+	}
+
+	#[test]
+	fn test_parse_invalid_utf8(){
+		let mut buf = BytesMut::with_capacity(128);
+
+		buf.put_u32(ids::SERIAL);
+		buf.put_u64(64u64);
+
+		buf.put_u8(3);
+		buf.put_u8(b'A');
+		buf.put_u8(0xFF);
+		buf.put_u8(0xFE);
+
+		assert_eq!(parse_buf(&mut buf), Err(ParseError::InvalidUtf8));
 	}
 }
@@ -1,16 +1,12 @@
 pub use crate::types::*;
 use deku::prelude::*;
 
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+use super::error::ParseError;
+
+#[derive(PartialEq, Debug, Default, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
 pub struct ImuInfo(pub i32, pub i32, pub i32);
 
-impl Default for ImuInfo {
-	fn default() -> ImuInfo {
-		ImuInfo(0, 0, 0)
-	}
-}
-
 #[derive(PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
 // TODO CRITICAL this aint null terminated...
@@ -22,10 +18,23 @@ pub struct StringWithLength {
 	str_data: Vec<u8>
 }
 
-impl ToString for StringWithLength {
-	fn to_string(&self) -> String {
+impl StringWithLength {
+	pub fn as_bytes(&self) -> &[u8] {
+		&self.str_data
+	}
+
+	pub(crate) fn validate(&self) -> Result<(), ParseError> {
+		match std::str::from_utf8(&self.str_data) {
+			Ok(_) => Ok(()),
+			Err(_) => Err(ParseError::InvalidUtf8)
+		}
+	}
+}
+
+impl std::fmt::Display for StringWithLength {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		// TODO: DO NOT USE EXPECT!!!
-		String::from_utf8(self.str_data.to_vec()).expect("Invalid UTF-8")
+		f.write_str(std::str::from_utf8(&self.str_data).expect("Invalid UTF-8"))
 	}
 }
 
@@ -43,9 +52,9 @@ impl Default for StringWithLength {
 #[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
 pub struct FirmwareString(pub StringWithLength);
 
-impl ToString for FirmwareString {
-	fn to_string(&self) -> String {
-		self.0.to_string()
+impl std::fmt::Display for FirmwareString {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		self.0.fmt(f)
 	}
 }
 
@@ -76,7 +85,7 @@ pub struct HandshakeData {
 	#[deku(cond = "deku::rest.len() >= 4", default = "0")]
 	pub firmware_build: i32,
 	
-	#[deku(cond = "deku::rest.len() >= 1")]
+	#[deku(cond = "!deku::rest.is_empty()")]
 	pub firmware: FirmwareString,
 	
 	#[deku(cond = "deku::rest.len() >= 6")]
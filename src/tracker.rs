@@ -1,21 +1,32 @@
-use std::{collections::HashMap, time::SystemTime};
+use std::{collections::{HashMap, VecDeque}, time::SystemTime};
 
-use crate::types::*;
+use crate::packet_parsing::types::*;
+
+// Maximum amount of serial lines kept per tracker
+const SERIAL_LOG_SIZE: usize = 64;
 
 #[derive(Debug)]
 pub struct Sensor {
-    pub last_quat: Quaternion
+    pub last_quat: Quaternion,
+    pub last_correction: Option<Quaternion>,
+    pub raw_magnetometer: Option<Vector>,
+    pub magnetometer_accuracy: Option<f32>,
+    pub status: Option<i8>
 }
 
 impl Default for Sensor {
     fn default() -> Self {
         Self {
-                last_quat: Quaternion {
+            last_quat: Quaternion {
                 x: 0.0,
                 y: 0.0,
                 z: 0.0,
                 w: 0.0
-            }
+            },
+            last_correction: None,
+            raw_magnetometer: None,
+            magnetometer_accuracy: None,
+            status: None
         }
     }
 }
@@ -25,22 +36,51 @@ impl Default for Sensor {
 pub struct TrackerData {
     pub sensors: HashMap<SensorID, Sensor>,
     pub last_heartbeat: SystemTime,
+
+    pub battery_level: Option<f32>,
+    pub last_tap: Option<(SensorID, TapData)>,
+    pub reset_reason: Option<ResetReasonData>,
+    pub last_ping: Option<PingId>,
+    pub serial_log: VecDeque<String>,
+
+    pub config: Option<DeviceConfig>,
+    pub raw_calibration: Option<RawCalibrationData>,
+    pub gyro_calibration: Option<GyroCalibrationData>,
 }
 
 
 impl TrackerData {
     pub fn get_sensor_or_default(&mut self, id: SensorID) -> &mut Sensor {
-        return self.sensors.entry(id).or_insert(Sensor::default())
+        return self.sensors.entry(id).or_default()
     }
 
     pub fn update_rotation(&mut self, id: SensorID, quat: Quaternion){
         self.get_sensor_or_default(id).last_quat = quat;
     }
+
+    pub fn push_serial_line(&mut self, line: String) {
+        if self.serial_log.len() >= SERIAL_LOG_SIZE {
+            self.serial_log.pop_front();
+        }
+
+        self.serial_log.push_back(line);
+    }
 }
 
 
 impl Default for TrackerData {
     fn default() -> Self {
-        Self { sensors: Default::default(), last_heartbeat: SystemTime::now() }
+        Self {
+            sensors: Default::default(),
+            last_heartbeat: SystemTime::now(),
+            battery_level: None,
+            last_tap: None,
+            reset_reason: None,
+            last_ping: None,
+            serial_log: Default::default(),
+            config: None,
+            raw_calibration: None,
+            gyro_calibration: None
+        }
     }
 }
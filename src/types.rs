@@ -48,7 +48,7 @@ pub struct CalibrationInfo(pub i8);
 
 // TODO: data gets reversed?? wtf
 // https://github.com/SlimeVR/SlimeVR-Server/blob/12d7f191ee4b737281cc2e3b04f01366bc67197c/src/main/java/io/eiren/vr/trackers/MPUTracker.java#L63
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(PartialEq, Debug, Default, Clone, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
 #[allow(non_snake_case)]
 pub struct CalibrationConfig {
@@ -61,7 +61,7 @@ pub struct CalibrationConfig {
     pub gyro_off: Vector
 }
 
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(PartialEq, Debug, Default, Clone, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
 pub struct DeviceConfig {
     pub calibration: CalibrationConfig,
@@ -1,6 +1,6 @@
 use deku::prelude::*;
 
-#[derive(PartialEq, Debug, DekuRead, DekuWrite, Clone, Copy)]
+#[derive(PartialEq, Debug, Default, DekuRead, DekuWrite, Clone, Copy)]
 #[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
 pub struct Vector {
 	pub x: f32,
@@ -9,7 +9,7 @@ pub struct Vector {
 }
 
 
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(PartialEq, Debug, Default, DekuRead, DekuWrite, Clone, Copy)]
 #[deku(endian = "endian", ctx = "endian: deku::ctx::Endian")]
 pub struct Matrix3x3 {
 	pub a00: f32,